@@ -0,0 +1,92 @@
+//! Budget/gas-bench harness: measures the resource budget each `SmokeContract`
+//! entry point consumes and fails the run if it regresses beyond
+//! `REGRESSION_THRESHOLD_PCT` against the committed `baseline.csv`.
+use smoke_contract::{SmokeContract, SmokeContractClient};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use std::{collections::HashMap, fs, process::exit};
+
+const BASELINE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/baseline.csv");
+const REGRESSION_THRESHOLD_PCT: f64 = 10.0;
+
+struct Measurement {
+    cpu_instructions: u64,
+    mem_bytes: u64,
+}
+
+fn measure(env: &Env, f: impl FnOnce()) -> Measurement {
+    env.budget().reset_default();
+    f();
+    Measurement {
+        cpu_instructions: env.budget().cpu_instruction_cost(),
+        mem_bytes: env.budget().memory_bytes_cost(),
+    }
+}
+
+fn load_baseline() -> HashMap<String, Measurement> {
+    let contents = fs::read_to_string(BASELINE_PATH).expect("missing benches/baseline.csv");
+    contents
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut cols = line.split(',');
+            let name = cols.next().expect("function column").to_string();
+            let cpu = cols.next().expect("cpu_instructions column").parse().unwrap();
+            let mem = cols.next().expect("mem_bytes column").parse().unwrap();
+            (name, Measurement { cpu_instructions: cpu, mem_bytes: mem })
+        })
+        .collect()
+}
+
+fn pct_delta(baseline: u64, current: u64) -> f64 {
+    if baseline == 0 {
+        return if current == 0 { 0.0 } else { 100.0 };
+    }
+    (current as f64 - baseline as f64) / baseline as f64 * 100.0
+}
+
+fn report(name: &str, current: &Measurement, baseline: Option<&Measurement>) -> bool {
+    let Some(baseline) = baseline else {
+        println!("{name:<28} cpu={:<10} mem={:<10} (no baseline recorded)", current.cpu_instructions, current.mem_bytes);
+        return true;
+    };
+
+    let cpu_delta = pct_delta(baseline.cpu_instructions, current.cpu_instructions);
+    let mem_delta = pct_delta(baseline.mem_bytes, current.mem_bytes);
+    println!(
+        "{name:<28} cpu={:<10} ({cpu_delta:+.1}%)  mem={:<10} ({mem_delta:+.1}%)",
+        current.cpu_instructions, current.mem_bytes
+    );
+    cpu_delta <= REGRESSION_THRESHOLD_PCT && mem_delta <= REGRESSION_THRESHOLD_PCT
+}
+
+fn main() {
+    let baseline = load_baseline();
+    let env = Env::default();
+    env.mock_all_auths();
+    let id = env.register_contract(None, SmokeContract);
+    let client = SmokeContractClient::new(&env, &id);
+    let user = Address::generate(&env);
+
+    let mut within_threshold = true;
+
+    let m = measure(&env, || {
+        client.no_auth_bump(&1);
+    });
+    within_threshold &= report("no_auth_bump", &m, baseline.get("no_auth_bump"));
+
+    let m = measure(&env, || {
+        client.write_with_address_auth(&user, &7, &1);
+    });
+    within_threshold &= report("write_with_address_auth", &m, baseline.get("write_with_address_auth"));
+
+    let m = measure(&env, || {
+        client.read_value(&user);
+    });
+    within_threshold &= report("read_value", &m, baseline.get("read_value"));
+
+    if !within_threshold {
+        eprintln!("budget regression exceeds {REGRESSION_THRESHOLD_PCT}% threshold against baseline.csv");
+        exit(1);
+    }
+}
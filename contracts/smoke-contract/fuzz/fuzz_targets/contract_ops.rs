@@ -0,0 +1,69 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use smoke_contract::{SmokeContract, SmokeContractClient};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+/// One step of a randomized call sequence against `SmokeContract`.
+#[derive(Arbitrary, Debug)]
+enum Op {
+    NoAuthBump(u32),
+    /// An authorized write attempt; `valid_auth` and `nonce` are independently
+    /// randomized so the harness exercises both the happy path and rejected
+    /// auth/nonce combinations.
+    AuthorizedWrite { value: u32, nonce: u64, valid_auth: bool },
+    Read,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    ops: Vec<Op>,
+}
+
+fuzz_target!(|input: Input| {
+    let env = Env::default();
+    let id = env.register_contract(None, SmokeContract);
+    let client = SmokeContractClient::new(&env, &id);
+    let user = Address::generate(&env);
+
+    // Mirrors the state the contract should end up in, so every `Read` can
+    // assert against it instead of just "didn't crash".
+    let mut last_value: u32 = 0;
+    let mut last_nonce: u64 = 0;
+
+    for op in input.ops {
+        match op {
+            Op::NoAuthBump(n) => {
+                assert_eq!(client.no_auth_bump(&n), n.saturating_add(1));
+            }
+            Op::AuthorizedWrite { value, nonce, valid_auth } => {
+                // Toggle whether `user` is authorized for this call: mocking all
+                // auths satisfies `require_auth`, while disabling mocking (an
+                // empty `set_auths`) leaves it with no valid auth at all.
+                if valid_auth {
+                    env.mock_all_auths();
+                } else {
+                    env.set_auths(&[]);
+                }
+
+                let accepted = valid_auth && nonce == last_nonce + 1;
+                // `try_*` surfaces a contract panic as an `Err` instead of
+                // unwinding, which is required here since cargo-fuzz builds
+                // with `panic = "abort"` and a real unwind would just crash
+                // the fuzzer on every expected rejection.
+                let result = client.try_write_with_address_auth(&user, &value, &nonce);
+
+                assert_eq!(result.is_ok(), accepted, "auth/nonce gate let an invalid write through");
+                if accepted {
+                    last_value = value;
+                    last_nonce = nonce;
+                }
+            }
+            Op::Read => {
+                assert_eq!(client.read_value(&user), last_value);
+                assert_eq!(client.current_nonce(&user), last_nonce);
+            }
+        }
+    }
+});
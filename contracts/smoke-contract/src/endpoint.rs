@@ -0,0 +1,166 @@
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, Env, Symbol};
+
+/// Storage keys for `EndpointContract`.
+///
+/// Outbound nonces are tracked per `(dst_chain_id, sender)` so each sender has its
+/// own ordered lane to every destination chain; inbound nonces are tracked per
+/// `(src_chain_id, src_address)` so delivery from a given remote sender is ordered
+/// and exactly-once.
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Relayer,
+    OutboundNonce(u32, Address),
+    InboundNonce(u32, Bytes),
+}
+
+/// A minimal LayerZero-style cross-chain messaging endpoint.
+///
+/// `send` is called by a local sender wishing to deliver a payload to a remote
+/// chain; `receive` is called by the relayer once it has observed and relayed
+/// that message, crediting it to the local destination in order.
+#[contract]
+pub struct EndpointContract;
+
+#[contractimpl]
+impl EndpointContract {
+    /// Configures the address allowed to call `receive` (the relayer/library).
+    /// One-time only: panics if a relayer is already configured, so an
+    /// already-initialized endpoint can't have its trusted relayer swapped
+    /// out from under it.
+    pub fn init(env: Env, relayer: Address) {
+        if env.storage().instance().has(&DataKey::Relayer) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Relayer, &relayer);
+    }
+
+    /// Emits an outbound message for the relayer to pick up and deliver on
+    /// `dst_chain_id`, bumping the sender's per-destination nonce.
+    pub fn send(
+        env: Env,
+        sender: Address,
+        dst_chain_id: u32,
+        destination: Bytes,
+        payload: Bytes,
+        refund: Address,
+    ) -> u64 {
+        sender.require_auth();
+
+        let store = env.storage().instance();
+        let nonce_key = DataKey::OutboundNonce(dst_chain_id, sender.clone());
+        let nonce: u64 = store.get(&nonce_key).unwrap_or(0) + 1;
+        store.set(&nonce_key, &nonce);
+
+        env.events().publish(
+            (Symbol::new(&env, "message_sent"), dst_chain_id),
+            (sender, destination, payload, refund, nonce),
+        );
+
+        nonce
+    }
+
+    /// Delivers a relayed message; requires auth from the configured relayer and
+    /// rejects anything but the next expected nonce for `(src_chain_id, src_address)`,
+    /// so delivery is ordered and exactly-once.
+    pub fn receive(env: Env, src_chain_id: u32, src_address: Bytes, nonce: u64, payload: Bytes) {
+        let relayer: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Relayer)
+            .expect("relayer not configured");
+        relayer.require_auth();
+
+        let store = env.storage().instance();
+        let nonce_key = DataKey::InboundNonce(src_chain_id, src_address.clone());
+        let last: u64 = store.get(&nonce_key).unwrap_or(0);
+        if nonce != last + 1 {
+            panic!("out of order or duplicate nonce");
+        }
+        store.set(&nonce_key, &nonce);
+
+        env.events().publish(
+            (Symbol::new(&env, "payload_received"), src_chain_id),
+            (src_address, nonce, payload),
+        );
+    }
+
+    /// Last nonce assigned by `send` for `(dst_chain_id, sender)`; 0 if none sent yet.
+    pub fn outbound_nonce(env: Env, dst_chain_id: u32, sender: Address) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::OutboundNonce(dst_chain_id, sender))
+            .unwrap_or(0)
+    }
+
+    /// Last nonce delivered from `(src_chain_id, src_address)`; 0 if none yet.
+    pub fn inbound_nonce(env: Env, src_chain_id: u32, src_address: Bytes) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::InboundNonce(src_chain_id, src_address))
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn ordered_exactly_once_delivery() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let id = env.register_contract(None, EndpointContract);
+        let client = EndpointContractClient::new(&env, &id);
+
+        let relayer = Address::generate(&env);
+        let sender = Address::generate(&env);
+        let refund = Address::generate(&env);
+        let destination = Bytes::from_array(&env, &[1, 2, 3]);
+        let src_address = Bytes::from_array(&env, &[4, 5, 6]);
+
+        client.init(&relayer);
+
+        let payload = Bytes::from_array(&env, &[7, 8, 9]);
+        let nonce = client.send(&sender, &2u32, &destination, &payload, &refund);
+        assert_eq!(nonce, 1);
+        assert_eq!(client.outbound_nonce(&2u32, &sender), 1);
+
+        client.receive(&1u32, &src_address, &1u64, &payload);
+        assert_eq!(client.inbound_nonce(&1u32, &src_address), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of order or duplicate nonce")]
+    fn rejects_duplicate_delivery() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let id = env.register_contract(None, EndpointContract);
+        let client = EndpointContractClient::new(&env, &id);
+
+        let relayer = Address::generate(&env);
+        let src_address = Bytes::from_array(&env, &[4, 5, 6]);
+        let payload = Bytes::from_array(&env, &[7, 8, 9]);
+
+        client.init(&relayer);
+        client.receive(&1u32, &src_address, &1u64, &payload);
+        client.receive(&1u32, &src_address, &1u64, &payload);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of order or duplicate nonce")]
+    fn rejects_out_of_order_delivery() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let id = env.register_contract(None, EndpointContract);
+        let client = EndpointContractClient::new(&env, &id);
+
+        let relayer = Address::generate(&env);
+        let src_address = Bytes::from_array(&env, &[4, 5, 6]);
+        let payload = Bytes::from_array(&env, &[7, 8, 9]);
+
+        client.init(&relayer);
+        client.receive(&1u32, &src_address, &2u64, &payload);
+    }
+}
@@ -0,0 +1,77 @@
+//! Regenerates the JSON interface spec and Rust client bindings the
+//! relayer-plugin-channels plugin uses to auto-discover contract entry
+//! points, rather than hardcoding them.
+//!
+//! Run from the repo root once a contract's wasm has been built:
+//!
+//!     cargo run -p gen-bindings -- <contract-name> <path/to/contract.wasm> <output-dir>
+//!
+//! The emitted JSON additionally flags which functions require address auth
+//! so the relayer can build the matching `require_auth_for_args` invocation
+//! automatically instead of hardcoding it per entry point.
+use sha2::{Digest, Sha256};
+use std::{env, fs, path::PathBuf, process::exit};
+
+/// Entry points known to call `require_auth`/`require_auth_for_args`, per
+/// contract. Not derivable from the XDR spec itself (auth isn't part of a
+/// function's type signature), so it's tracked here alongside the generator
+/// and kept in sync with the contract source by the request/review process.
+fn auth_required_fns(contract_name: &str) -> &'static [&'static str] {
+    match contract_name {
+        "SmokeContract" => &["write_with_address_auth", "open_channel", "settle"],
+        "EndpointContract" => &["send", "receive"],
+        _ => &[],
+    }
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let (contract_name, wasm_path, out_dir) = match (args.next(), args.next(), args.next()) {
+        (Some(a), Some(b), Some(c)) => (a, PathBuf::from(b), PathBuf::from(c)),
+        _ => {
+            eprintln!("usage: gen-bindings <contract-name> <path/to/contract.wasm> <output-dir>");
+            exit(1);
+        }
+    };
+
+    let wasm = fs::read(&wasm_path).unwrap_or_else(|e| {
+        eprintln!("failed to read {}: {e}", wasm_path.display());
+        exit(1);
+    });
+
+    let entries = soroban_spec::read::from_wasm(&wasm).unwrap_or_else(|e| {
+        eprintln!("failed to parse spec from {}: {e}", wasm_path.display());
+        exit(1);
+    });
+
+    let json = annotate_auth(&soroban_spec_json::generate(&entries), auth_required_fns(&contract_name));
+    let client_file_name = format!("{contract_name}_client.rs");
+    let sha256 = hex::encode(Sha256::digest(&wasm));
+    let rust_client = soroban_spec_rust::generate(&entries, &client_file_name, &sha256).to_string();
+
+    fs::create_dir_all(&out_dir).unwrap();
+    fs::write(out_dir.join(format!("{contract_name}_spec.json")), json).unwrap();
+    fs::write(out_dir.join(&client_file_name), rust_client).unwrap();
+
+    println!("wrote bindings for {contract_name} to {}", out_dir.display());
+}
+
+/// Adds a `requiresAuth` flag to each function entry of an already-rendered
+/// interface spec, so the relayer doesn't have to hardcode which entry
+/// points need a `require_auth_for_args` invocation.
+fn annotate_auth(spec_json: &str, auth_required: &[&str]) -> String {
+    let mut spec: serde_json::Value = serde_json::from_str(spec_json).expect("spec is valid JSON");
+    if let Some(entries) = spec.as_array_mut() {
+        for entry in entries {
+            if entry.get("type").and_then(|t| t.as_str()) != Some("function") {
+                continue;
+            }
+            let requires_auth = entry
+                .get("name")
+                .and_then(|name| name.as_str())
+                .is_some_and(|name| auth_required.contains(&name));
+            entry["requiresAuth"] = serde_json::Value::Bool(requires_auth);
+        }
+    }
+    serde_json::to_string_pretty(&spec).expect("annotated spec serializes")
+}